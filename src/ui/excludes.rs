@@ -0,0 +1,95 @@
+//! Ignore/exclude matching for the file index.
+//!
+//! Combines the `.gitignore`/`.ignore` rules found at the source root with
+//! user-supplied glob patterns from the Filters panel, so the default file set
+//! skips `target/`, `node_modules/`, lockfiles and anything else the project
+//! already ignores — keeping the assembled LLM context clean and token-efficient.
+
+use std::path::{Path, PathBuf};
+
+use glob::Pattern;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+
+/// A compiled set of exclusion rules rooted at the source directory.
+pub struct ExcludeMatcher {
+    root: PathBuf,
+    gitignore: Gitignore,
+    globs: Vec<Pattern>,
+}
+
+impl ExcludeMatcher {
+    /// Build a matcher for `root`, loading any `.gitignore`/`.ignore` files at
+    /// the root and compiling the user-supplied `globs`. Invalid globs are
+    /// skipped with a log line rather than failing the whole reload.
+    pub fn build(root: &str, globs: &[String]) -> Self {
+        let root = PathBuf::from(root);
+        let mut builder = GitignoreBuilder::new(&root);
+        for name in [".gitignore", ".ignore"] {
+            let path = root.join(name);
+            if path.exists() {
+                if let Some(e) = builder.add(&path) {
+                    log::warn!("Failed to read {}: {}", path.display(), e);
+                }
+            }
+        }
+        let gitignore = builder.build().unwrap_or_else(|e| {
+            log::warn!("Failed to compile ignore rules: {}", e);
+            Gitignore::empty()
+        });
+        let globs = globs
+            .iter()
+            .filter(|g| !g.trim().is_empty())
+            .filter_map(|g| match Pattern::new(g) {
+                Ok(p) => Some(p),
+                Err(e) => {
+                    log::warn!("Ignoring invalid exclude glob {:?}: {}", g, e);
+                    None
+                }
+            })
+            .collect();
+        Self {
+            root,
+            gitignore,
+            globs,
+        }
+    }
+
+    /// Whether `path` should be dropped from the index. Ignore rules are matched
+    /// relative to the root; globs are matched against both the root-relative
+    /// path and the bare file name so patterns like `*.lock` work without a leading path.
+    pub fn is_excluded(&self, path: &str) -> bool {
+        let p = Path::new(path);
+        let rel = p.strip_prefix(&self.root).unwrap_or(p);
+        if self
+            .gitignore
+            .matched_path_or_any_parents(rel, false)
+            .is_ignore()
+        {
+            return true;
+        }
+        let name = p.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        self.globs
+            .iter()
+            .any(|g| g.matches_path(rel) || g.matches(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn directory_ignore_rules_cascade_to_children() {
+        let dir = std::env::temp_dir().join(format!("excludes-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(".gitignore"), "target/\n").unwrap();
+
+        let matcher = ExcludeMatcher::build(dir.to_str().unwrap(), &[]);
+
+        let child = dir.join("target").join("debug").join("foo.rs");
+        assert!(matcher.is_excluded(child.to_str().unwrap()));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
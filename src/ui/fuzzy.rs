@@ -0,0 +1,93 @@
+//! Subsequence fuzzy matching used by the Source Files filter.
+//!
+//! A query matches a candidate when every character of the query appears in the
+//! candidate, in order (case-insensitive). Matches are scored so that
+//! consecutive characters and characters landing on word / path-separator
+//! boundaries rank ahead of loose, scattered matches.
+
+/// Bonus for a character that immediately follows the previous match.
+const CONSECUTIVE_BONUS: i32 = 15;
+/// Bonus for a character that starts a new path segment or word.
+const BOUNDARY_BONUS: i32 = 30;
+/// Penalty applied per skipped character between two matches.
+const GAP_PENALTY: i32 = 2;
+
+fn is_boundary(prev: Option<char>) -> bool {
+    match prev {
+        None => true,
+        Some(c) => c == '/' || c == '_' || c == '-' || c == '.' || c == ' ',
+    }
+}
+
+/// Score `candidate` against `query`, returning `None` when `query` is not a
+/// subsequence of `candidate`. Higher scores are better matches. An empty query
+/// matches everything with a neutral score of `0`.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let cand: Vec<char> = candidate.chars().collect();
+    let mut score = 0;
+    let mut last_match: Option<usize> = None;
+    let mut query_chars = query.chars().map(|c| c.to_ascii_lowercase());
+    let mut needle = query_chars.next();
+
+    for (i, &ch) in cand.iter().enumerate() {
+        let Some(want) = needle else { break };
+        if ch.to_ascii_lowercase() == want {
+            score += match last_match {
+                Some(prev) if prev + 1 == i => CONSECUTIVE_BONUS,
+                Some(prev) => -GAP_PENALTY * (i - prev - 1) as i32,
+                None => 0,
+            };
+            if is_boundary(if i == 0 { None } else { Some(cand[i - 1]) }) {
+                score += BOUNDARY_BONUS;
+            }
+            last_match = Some(i);
+            needle = query_chars.next();
+        }
+    }
+
+    if needle.is_some() {
+        None
+    } else {
+        Some(score)
+    }
+}
+
+/// Return the indices of `candidates` that match `query`, ordered by descending
+/// score (ties broken by original order for a stable result).
+pub fn filter_indices<S: AsRef<str>>(query: &str, candidates: &[S]) -> Vec<usize> {
+    let mut scored: Vec<(usize, i32)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| fuzzy_match(query, c.as_ref()).map(|s| (i, s)))
+        .collect();
+    scored.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    scored.into_iter().map(|(i, _)| i).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_matches_regardless_of_case() {
+        assert!(fuzzy_match("src", "SRC/main.rs").is_some());
+        assert!(fuzzy_match("xyz", "main.rs").is_none());
+    }
+
+    #[test]
+    fn boundary_matches_score_higher_than_scattered_ones() {
+        let boundary = fuzzy_match("mr", "main.rs").unwrap();
+        let scattered = fuzzy_match("mr", "mixer").unwrap();
+        assert!(boundary > scattered);
+    }
+
+    #[test]
+    fn filter_indices_drops_non_matches_and_keeps_ties_in_original_order() {
+        let candidates = ["src/main.rs", "src/ui/mod.rs", "src/ui/excludes.rs"];
+        let order = filter_indices("ui", &candidates);
+        assert_eq!(order, vec![1, 2]);
+    }
+}
@@ -0,0 +1,136 @@
+//! User-configurable keybindings.
+//!
+//! Incoming [`KeyEvent`]s are resolved to an [`Action`] through a [`Bindings`]
+//! map before `App::update` dispatches them, so the generate / reload / clear
+//! keys (and panel navigation) can be remapped from a TOML config. When no
+//! config file exists the built-in defaults reproduce the original hardcoded
+//! F-key layout.
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use serde::{Deserialize, Serialize};
+
+/// A semantic action the UI can perform, independent of which key triggers it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    ReloadFiles,
+    Generate,
+    ClearField,
+    OpenProfiles,
+    NextPanel,
+    PrevPanel,
+    ToggleSelect,
+    Exit,
+}
+
+/// Resolved keybindings: a canonical key string (see [`canonical`]) to the
+/// action it triggers.
+pub struct Bindings {
+    by_key: HashMap<String, Action>,
+}
+
+impl Bindings {
+    /// Load bindings from `$XDG_CONFIG_HOME/aianvil/keybindings.toml`, falling
+    /// back to [`Bindings::defaults`] for any action the file doesn't override
+    /// (and entirely if the file is missing or malformed).
+    pub fn load() -> Self {
+        let mut bindings = Self::defaults();
+        if let Some(raw) = read_config() {
+            match toml::from_str::<HashMap<Action, String>>(&raw) {
+                Ok(overrides) => {
+                    // An explicit config fully replaces the default for every
+                    // action it mentions, key side included.
+                    for (action, key) in overrides {
+                        bindings.by_key.retain(|_, a| *a != action);
+                        bindings.by_key.insert(canonical_str(&key), action);
+                    }
+                }
+                Err(e) => log::error!("Failed to parse keybindings config: {}", e),
+            }
+        }
+        bindings
+    }
+
+    /// The built-in layout: the keys that were previously hardcoded in `update`
+    /// (F1, F2, F3, F10, space), plus F4 for profiles and tab / shift+tab for
+    /// panel navigation.
+    pub fn defaults() -> Self {
+        let mut by_key = HashMap::new();
+        by_key.insert("f1".to_string(), Action::ReloadFiles);
+        by_key.insert("f2".to_string(), Action::Generate);
+        by_key.insert("f3".to_string(), Action::ClearField);
+        by_key.insert("f4".to_string(), Action::OpenProfiles);
+        by_key.insert("f10".to_string(), Action::Exit);
+        by_key.insert("space".to_string(), Action::ToggleSelect);
+        by_key.insert("tab".to_string(), Action::NextPanel);
+        by_key.insert("backtab".to_string(), Action::PrevPanel);
+        Self { by_key }
+    }
+
+    /// Resolve a key event to its bound action, if any.
+    pub fn resolve(&self, key_event: &KeyEvent) -> Option<Action> {
+        canonical(key_event.code).and_then(|k| self.by_key.get(&k).copied())
+    }
+
+    /// Human-readable label for the key bound to `action` (e.g. `"F1"`), for
+    /// rendering the hint bar. Returns `"?"` when the action is unbound.
+    pub fn label(&self, action: Action) -> String {
+        self.by_key
+            .iter()
+            .find(|(_, a)| **a == action)
+            .map(|(k, _)| display_label(k))
+            .unwrap_or_else(|| "?".to_string())
+    }
+}
+
+/// Canonical string for a key code, or `None` for codes we don't bind.
+fn canonical(code: KeyCode) -> Option<String> {
+    match code {
+        KeyCode::F(n) => Some(format!("f{}", n)),
+        KeyCode::Char(' ') => Some("space".to_string()),
+        KeyCode::Char(c) => Some(c.to_ascii_lowercase().to_string()),
+        KeyCode::Enter => Some("enter".to_string()),
+        KeyCode::Esc => Some("esc".to_string()),
+        KeyCode::Tab => Some("tab".to_string()),
+        KeyCode::BackTab => Some("backtab".to_string()),
+        _ => None,
+    }
+}
+
+/// Normalize a key string from the config into the same canonical form
+/// produced by [`canonical`], so a config entry matches the key it's meant to
+/// rebind (e.g. `shift+tab`, the label `display_label` shows for `backtab`,
+/// must normalize back to `backtab`).
+fn canonical_str(key: &str) -> String {
+    let key = key.trim();
+    match key.to_ascii_lowercase().as_str() {
+        "space" => "space".to_string(),
+        "shift+tab" | "shift-tab" => "backtab".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn display_label(canonical: &str) -> String {
+    if let Some(num) = canonical.strip_prefix('f') {
+        if num.chars().all(|c| c.is_ascii_digit()) && !num.is_empty() {
+            return format!("F{}", num);
+        }
+    }
+    match canonical {
+        "space" => "space".to_string(),
+        "esc" => "esc".to_string(),
+        "enter" => "enter".to_string(),
+        "tab" => "tab".to_string(),
+        "backtab" => "shift+tab".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn read_config() -> Option<String> {
+    let path = xdg::BaseDirectories::with_prefix("aianvil")
+        .ok()?
+        .find_config_file("keybindings.toml")?;
+    std::fs::read_to_string(path).ok()
+}
@@ -1,9 +1,12 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use crossterm::event::{KeyCode, KeyEvent};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Alignment},
     style::{Color, Style},
+    text::Text,
     widgets::{Block, Borders, Paragraph, Clear},
     Frame,
 };
@@ -18,12 +21,46 @@ pub mod filters;
 pub mod source_files;
 pub mod output_file;
 pub mod output;
+pub mod preview;
+pub mod fuzzy;
+pub mod profiles;
+pub mod keybind;
+pub mod excludes;
+
+/// A filesystem change reported by the background [`notify`] watcher, one per
+/// raw event; bursts are coalesced later in `process_fs_events`. The affected paths let the app invalidate just the files that changed instead
+/// of recomputing every token count on every event.
+pub struct FsEvent {
+    pub paths: Vec<String>,
+}
+
+/// Events arriving closer together than this are coalesced into a single reload.
+const FS_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Stable string label for an output destination, used in persisted profiles.
+fn destination_label(dest: &OutputDestination) -> &'static str {
+    match dest {
+        OutputDestination::File => "file",
+        OutputDestination::Clipboard => "clipboard",
+        OutputDestination::FileAndClipboard => "file_and_clipboard",
+    }
+}
+
+/// Inverse of [`destination_label`]; unknown labels fall back to `Clipboard`.
+fn destination_from_label(label: &str) -> OutputDestination {
+    match label {
+        "file" => OutputDestination::File,
+        "file_and_clipboard" => OutputDestination::FileAndClipboard,
+        _ => OutputDestination::Clipboard,
+    }
+}
 
 #[derive(Copy, Clone, PartialEq)]
 pub enum FocusedPanel {
     SourcePath,
     Filters,
     SourceFiles,
+    Preview,
     Output,
     OutputFile,
 }
@@ -33,7 +70,8 @@ impl FocusedPanel {
         match self {
             FocusedPanel::SourcePath => FocusedPanel::Filters,
             FocusedPanel::Filters => FocusedPanel::SourceFiles,
-            FocusedPanel::SourceFiles => FocusedPanel::Output,
+            FocusedPanel::SourceFiles => FocusedPanel::Preview,
+            FocusedPanel::Preview => FocusedPanel::Output,
             FocusedPanel::Output => {
                 if app.output_panel.destination == OutputDestination::Clipboard {
                     FocusedPanel::SourcePath
@@ -49,7 +87,8 @@ impl FocusedPanel {
             FocusedPanel::SourcePath => FocusedPanel::OutputFile,
             FocusedPanel::Filters => FocusedPanel::SourcePath,
             FocusedPanel::SourceFiles => FocusedPanel::Filters,
-            FocusedPanel::Output => FocusedPanel::SourceFiles,
+            FocusedPanel::Preview => FocusedPanel::SourceFiles,
+            FocusedPanel::Output => FocusedPanel::Preview,
             FocusedPanel::OutputFile => {
                 if app.output_panel.destination == OutputDestination::Clipboard {
                     FocusedPanel::Output
@@ -65,6 +104,7 @@ pub struct App {
     pub source_path_panel: source_path::SourcePathPanel,
     pub filters_panel: filters::FiltersPanel,
     pub source_files_panel: SourceFilesPanel,
+    pub preview_panel: preview::PreviewPanel,
     pub output_panel: OutputPanel,
     pub output_file_panel: output_file::OutputFilePanel,
     pub focused_panel: FocusedPanel,
@@ -73,6 +113,7 @@ pub struct App {
     pub selected_files: HashSet<String>,
     pub processing: bool,
     pub filter_config: FilterConfig,
+    pub exclude_globs: Vec<String>,
     pub text_source: Option<Arc<dyn TextSource>>,
     pub exit_requested: bool,
     pub reload_files_needed: bool,
@@ -80,15 +121,31 @@ pub struct App {
     pub prev_source_path: String,
     pub token_count_tx: mpsc::UnboundedSender<(String, Result<usize, String>)>,
     pub token_count_rx: mpsc::UnboundedReceiver<(String, Result<usize, String>)>,
+    pub preview_tx: mpsc::UnboundedSender<(String, Result<Text<'static>, String>)>,
+    pub preview_rx: mpsc::UnboundedReceiver<(String, Result<Text<'static>, String>)>,
+    pub fs_event_tx: mpsc::UnboundedSender<FsEvent>,
+    pub fs_event_rx: mpsc::UnboundedReceiver<FsEvent>,
+    watcher: Option<RecommendedWatcher>,
+    watched_path: Option<String>,
+    fs_reload_pending: Option<Instant>,
+    pub bindings: keybind::Bindings,
+    pub profiles_panel: profiles::ProfilesPanel,
+    pub profiles_open: bool,
+    /// Selection to re-apply once `reload_files_immediate` has rebuilt the index
+    /// after loading a profile (intersected against the fresh index).
+    pending_profile_selection: Option<HashSet<String>>,
 }
 
 impl App {
     pub fn new(default_path: String, default_output_path: String) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
+        let (preview_tx, preview_rx) = mpsc::unbounded_channel();
+        let (fs_event_tx, fs_event_rx) = mpsc::unbounded_channel();
         Self {
             source_path_panel: source_path::SourcePathPanel::new(default_path.clone()),
             filters_panel: filters::FiltersPanel::new(),
             source_files_panel: SourceFilesPanel::new(),
+            preview_panel: preview::PreviewPanel::new(),
             output_panel: OutputPanel::new(),
             output_file_panel: output_file::OutputFilePanel::new(default_output_path),
             focused_panel: FocusedPanel::SourcePath,
@@ -97,6 +154,7 @@ impl App {
             selected_files: HashSet::new(),
             processing: false,
             filter_config: FilterConfig::new(),
+            exclude_globs: Vec::new(),
             text_source: None,
             exit_requested: false,
             reload_files_needed: false,
@@ -104,11 +162,25 @@ impl App {
             prev_source_path: default_path,
             token_count_tx: tx,
             token_count_rx: rx,
+            preview_tx,
+            preview_rx,
+            fs_event_tx,
+            fs_event_rx,
+            watcher: None,
+            watched_path: None,
+            fs_reload_pending: None,
+            bindings: keybind::Bindings::load(),
+            profiles_panel: profiles::ProfilesPanel::new(),
+            profiles_open: false,
+            pending_profile_selection: None,
         }
     }
 
     pub fn draw(&mut self, f: &mut Frame) {
         self.process_token_count_results();
+        self.process_preview_results();
+        self.process_fs_events();
+        self.refresh_preview();
         let show_output_file = self.output_panel.destination != OutputDestination::Clipboard;
         let mut row_constraints = vec![
             Constraint::Length(3),
@@ -133,7 +205,11 @@ impl App {
 
         let mid = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Length(30), Constraint::Min(10)])
+            .constraints([
+                Constraint::Length(30),
+                Constraint::Min(10),
+                Constraint::Percentage(40),
+            ])
             .split(main_chunks[1]);
 
         self.filters_panel.draw(
@@ -150,6 +226,12 @@ impl App {
             &self.selected_files
         );
 
+        self.preview_panel.draw(
+            f,
+            mid[2],
+            self.focused_panel == FocusedPanel::Preview
+        );
+
         self.output_panel.draw(
             f,
             main_chunks[2],
@@ -172,27 +254,43 @@ impl App {
         if self.processing {
             self.draw_overlay(f);
         }
+
+        if self.profiles_open {
+            let modal = self.centered_rect(50, 15, f.area());
+            self.profiles_panel.draw(f, modal);
+        }
     }
 
     fn get_bottom_text(&self) -> String {
+        use keybind::Action;
+        // Render the keys actually bound to each action rather than literal
+        // F-key strings, so a remapped config shows the user's own keys.
+        let reload = self.bindings.label(Action::ReloadFiles);
+        let generate = self.bindings.label(Action::Generate);
+        let clear = self.bindings.label(Action::ClearField);
+        let profiles = self.bindings.label(Action::OpenProfiles);
+        let exit = self.bindings.label(Action::Exit);
+        let select = self.bindings.label(Action::ToggleSelect);
         match self.focused_panel {
             FocusedPanel::SourcePath =>
-                "enter - focus Filters  •  F1 - reload  •  F2 - generate  •  F3 - clear  •  F10/esc - close".to_string(),
+                format!("enter - focus Filters  •  {reload} - reload  •  {generate} - generate  •  {clear} - clear  •  {profiles} - profiles  •  {exit}/esc - close"),
             FocusedPanel::Filters =>
-                "↑/↓ - navigate  •  space - (de)select  •  enter - focus Files  •  esc - focus Source  •  F1 - reload  •  F2 - generate  •  F10 - close".to_string(),
+                format!("↑/↓ - navigate  •  {select} - (de)select  •  a - (de)select all  •  enter - focus Files  •  esc - focus Source  •  {reload} - reload  •  {generate} - generate  •  {exit} - close"),
             FocusedPanel::SourceFiles =>
-                "↑/↓ - navigate  •  space - (de)select  •  enter - count tokens & focus Output  •  esc - focus Filters  •  F1 - reload  •  F2 - generate  •  F10 - close".to_string(),
+                format!("↑/↓ - navigate  •  {select} - (de)select  •  a/i/c - all/invert/clear  •  / - filter  •  enter - count tokens & focus Output  •  esc - focus Filters  •  {reload} - reload  •  {generate} - generate  •  {exit} - close"),
+            FocusedPanel::Preview =>
+                format!("PgUp/PgDn - scroll  •  enter - focus Output  •  esc - focus Files  •  {reload} - reload  •  {generate} - generate  •  {exit} - close"),
             FocusedPanel::Output => {
                 match self.output_panel.destination {
                     OutputDestination::File |
                     OutputDestination::FileAndClipboard =>
-                        "←/→ - toggle  •  enter - focus Output File  •  esc - focus Files  •  F1 - reload  •  F2 - generate  •  F10 - close".to_string(),
+                        format!("←/→ - toggle  •  enter - focus Output File  •  esc - focus Preview  •  {reload} - reload  •  {generate} - generate  •  {exit} - close"),
                     OutputDestination::Clipboard =>
-                        "←/→ - toggle  •  enter/F2 - generate  •  esc - focus Files  •  F1 - reload  •  F10 - close".to_string()
+                        format!("←/→ - toggle  •  enter/{generate} - generate  •  esc - focus Preview  •  {reload} - reload  •  {exit} - close"),
                 }
             }
             FocusedPanel::OutputFile =>
-                "enter/F2 - generate  •  esc - focus Output  •  F1 - reload  •  F3 - clear  •  F10 - close".to_string()
+                format!("enter/{generate} - generate  •  esc - focus Output  •  {reload} - reload  •  {clear} - clear  •  {exit} - close"),
         }
     }
 
@@ -221,98 +319,233 @@ impl App {
     }
 
     pub async fn update(&mut self, key_event: KeyEvent) {
+        if self.profiles_open {
+            self.handle_profiles_key(key_event);
+            return;
+        }
         let old_focused_panel = self.focused_panel;
-        match key_event.code {
-            KeyCode::F(n) if n == 10 => {
-                self.exit_requested = true;
-            }
-            KeyCode::Esc => {
-                if self.focused_panel == FocusedPanel::SourcePath {
-                    self.exit_requested = true;
-                } else {
-                    self.focused_panel = self.focused_panel.prev_panel(self);
-                    self.set_cursor_to_end();
+        // Resolve configurable keys (reload/generate/clear/profiles/toggle/…)
+        // to an Action first; unbound keys fall through to the literal handling
+        // of editing, navigation, and panel-local commands.
+        let filtering_source_files = self.focused_panel == FocusedPanel::SourceFiles
+            && self.source_files_panel.filter_active();
+        let resolved = self
+            .bindings
+            .resolve(&key_event)
+            .filter(|action| !(filtering_source_files && *action == keybind::Action::ToggleSelect));
+        if let Some(action) = resolved {
+            self.dispatch_action(action).await;
+        } else {
+            match key_event.code {
+                KeyCode::Esc => {
+                    if self.focused_panel == FocusedPanel::SourceFiles
+                        && self.source_files_panel.filter_active()
+                    {
+                        // Escape clears the filter and restores the full list;
+                        // focus stays put until the next Escape.
+                        self.source_files_panel.clear_filter(&self.loaded_files);
+                    } else if self.focused_panel == FocusedPanel::SourcePath {
+                        self.exit_requested = true;
+                    } else {
+                        self.focused_panel = self.focused_panel.prev_panel(self);
+                        self.set_cursor_to_end();
+                    }
+                }
+                KeyCode::Char('/')
+                    if self.focused_panel == FocusedPanel::SourceFiles
+                        && !self.source_files_panel.filter_active() =>
+                {
+                    self.source_files_panel.enter_filter_mode();
+                }
+                KeyCode::PageUp => {
+                    self.preview_panel.scroll_up();
+                }
+                KeyCode::PageDown => {
+                    self.preview_panel.scroll_down();
+                }
+                KeyCode::Enter => {
+                    self.handle_enter().await;
+                }
+                KeyCode::Char('a')
+                    if self.focused_panel == FocusedPanel::SourceFiles
+                        && !self.source_files_panel.filter_active() =>
+                {
+                    self.source_files_panel.select_all_visible(&mut self.selected_files);
+                    self.after_bulk_selection();
+                }
+                KeyCode::Char('i')
+                    if self.focused_panel == FocusedPanel::SourceFiles
+                        && !self.source_files_panel.filter_active() =>
+                {
+                    self.source_files_panel.invert_selection(&mut self.selected_files);
+                    self.after_bulk_selection();
+                }
+                KeyCode::Char('c')
+                    if self.focused_panel == FocusedPanel::SourceFiles
+                        && !self.source_files_panel.filter_active() =>
+                {
+                    self.source_files_panel.clear_selection(&mut self.selected_files);
+                    self.after_bulk_selection();
+                }
+                KeyCode::Char('a') if self.focused_panel == FocusedPanel::Filters => {
+                    self.filters_panel.toggle_all(
+                        &mut self.selected_extensions,
+                        &mut self.selected_files,
+                        &self.loaded_files,
+                    );
+                    self.after_bulk_selection();
+                }
+                _ => {
+                    match self.focused_panel {
+                        FocusedPanel::SourcePath => {
+                            self.source_path_panel.handle_input(key_event);
+                        }
+                        FocusedPanel::Filters => {
+                            self.filters_panel.handle_input(key_event);
+                        }
+                        FocusedPanel::SourceFiles => {
+                            self.source_files_panel.handle_input(key_event);
+                        }
+                        FocusedPanel::Preview => {}
+                        FocusedPanel::Output => {
+                            self.output_panel.handle_input(key_event);
+                        }
+                        FocusedPanel::OutputFile => {
+                            self.output_file_panel.handle_input(key_event);
+                        }
+                    }
                 }
             }
-            KeyCode::F(n) if n == 1 => {
+        }
+        let new_focused_panel = self.focused_panel;
+        if old_focused_panel == FocusedPanel::SourcePath && new_focused_panel != FocusedPanel::SourcePath {
+            if self.source_path_panel.value != self.prev_source_path {
+                self.reload_files_needed = true;
+                self.prev_source_path = self.source_path_panel.value.clone();
+            }
+        }
+    }
+
+    /// Perform a keybound [`Action`]. These are the commands the user can remap;
+    /// contextual keys (Enter, Esc, text entry) stay literal in `update`.
+    async fn dispatch_action(&mut self, action: keybind::Action) {
+        use keybind::Action;
+        match action {
+            Action::Exit => self.exit_requested = true,
+            Action::OpenProfiles => {
+                self.profiles_panel.refresh();
+                self.profiles_open = true;
+            }
+            Action::ReloadFiles => {
                 if !self.processing {
                     self.reload_files_needed = true;
                 }
             }
-            KeyCode::F(n) if n == 2 => {
+            Action::Generate => {
                 if !self.processing {
                     self.merge_needed = true;
                 }
             }
-            KeyCode::F(n) if n == 3 => {
-                match self.focused_panel {
-                    FocusedPanel::SourcePath => {
-                        self.source_path_panel.value.clear();
-                        self.source_path_panel.cursor_pos = 0;
-                    }
-                    FocusedPanel::OutputFile => {
-                        self.output_file_panel.value.clear();
-                        self.output_file_panel.cursor_pos = 0;
-                    }
-                    _ => {}
+            Action::ClearField => match self.focused_panel {
+                FocusedPanel::SourcePath => {
+                    self.source_path_panel.value.clear();
+                    self.source_path_panel.cursor_pos = 0;
+                }
+                FocusedPanel::OutputFile => {
+                    self.output_file_panel.value.clear();
+                    self.output_file_panel.cursor_pos = 0;
                 }
+                _ => {}
+            },
+            Action::NextPanel => {
+                self.focused_panel = self.focused_panel.next_panel(self);
+                self.set_cursor_to_end();
             }
-            KeyCode::Enter => {
-                self.handle_enter().await;
+            Action::PrevPanel => {
+                self.focused_panel = self.focused_panel.prev_panel(self);
+                self.set_cursor_to_end();
             }
-            KeyCode::Char(' ') => {
-                match self.focused_panel {
-                    FocusedPanel::Filters => {
-                        self.filters_panel.toggle_selected(
-                            &mut self.selected_extensions,
-                            &mut self.selected_files,
-                            &self.loaded_files
-                        );
-                    }
-                    FocusedPanel::SourceFiles => {
-                        self.source_files_panel.toggle_selected(
-                            &mut self.selected_extensions,
-                            &mut self.selected_files,
-                            &self.loaded_files
-                        );
-                    }
-                    _ => {}
+            Action::ToggleSelect => match self.focused_panel {
+                FocusedPanel::Filters => {
+                    self.filters_panel.toggle_selected(
+                        &mut self.selected_extensions,
+                        &mut self.selected_files,
+                        &self.loaded_files,
+                    );
+                }
+                FocusedPanel::SourceFiles => {
+                    self.source_files_panel.toggle_selected(
+                        &mut self.selected_extensions,
+                        &mut self.selected_files,
+                        &self.loaded_files,
+                    );
                 }
+                _ => {}
+            },
+        }
+    }
+
+    fn handle_profiles_key(&mut self, key_event: KeyEvent) {
+        match self.profiles_panel.handle_input(key_event) {
+            profiles::ProfileAction::None => {}
+            profiles::ProfileAction::Close => self.profiles_open = false,
+            profiles::ProfileAction::Save(name) => {
+                let profile = self.current_profile();
+                if let Err(e) = self.profiles_panel.store.save(&name, &profile) {
+                    log::error!("Failed to save profile {}: {}", name, e);
+                }
+                self.profiles_panel.refresh();
             }
-            _ => {
-                match self.focused_panel {
-                    FocusedPanel::SourcePath => {
-                        self.source_path_panel.handle_input(key_event);
-                    }
-                    FocusedPanel::Filters => {
-                        self.filters_panel.handle_input(key_event);
-                    }
-                    FocusedPanel::SourceFiles => {
-                        self.source_files_panel.handle_input(key_event);
-                    }
-                    FocusedPanel::Output => {
-                        self.output_panel.handle_input(key_event);
-                    }
-                    FocusedPanel::OutputFile => {
-                        self.output_file_panel.handle_input(key_event);
-                    }
+            profiles::ProfileAction::Delete(name) => {
+                if let Err(e) = self.profiles_panel.store.delete(&name) {
+                    log::error!("Failed to delete profile {}: {}", name, e);
                 }
+                self.profiles_panel.refresh();
             }
-        }
-        let new_focused_panel = self.focused_panel;
-        if old_focused_panel == FocusedPanel::SourcePath && new_focused_panel != FocusedPanel::SourcePath {
-            if self.source_path_panel.value != self.prev_source_path {
-                self.reload_files_needed = true;
-                self.prev_source_path = self.source_path_panel.value.clone();
+            profiles::ProfileAction::Load(name) => {
+                match self.profiles_panel.store.load(&name) {
+                    Ok(profile) => self.apply_profile(profile),
+                    Err(e) => log::error!("Failed to load profile {}: {}", name, e),
+                }
+                self.profiles_open = false;
             }
         }
     }
 
+    /// Snapshot the current working set into a [`Profile`].
+    fn current_profile(&self) -> profiles::Profile {
+        profiles::Profile {
+            source_path: self.source_path_panel.value.clone(),
+            filter_config: self.filter_config.clone(),
+            selected_extensions: self.selected_extensions.clone(),
+            selected_files: self.selected_files.clone(),
+            output_destination: destination_label(&self.output_panel.destination).to_string(),
+            output_path: self.output_file_panel.value.clone(),
+        }
+    }
+
+    /// Apply a loaded profile. The file selection can't be applied until the
+    /// index is rebuilt, so it's stashed in `pending_profile_selection` and
+    /// re-applied at the tail of `reload_files_immediate`.
+    fn apply_profile(&mut self, profile: profiles::Profile) {
+        self.source_path_panel.value = profile.source_path.clone();
+        self.source_path_panel.cursor_pos = self.source_path_panel.value.len();
+        self.prev_source_path = profile.source_path;
+        self.filter_config = profile.filter_config;
+        self.selected_extensions = profile.selected_extensions;
+        self.output_panel.destination = destination_from_label(&profile.output_destination);
+        self.output_file_panel.value = profile.output_path;
+        self.output_file_panel.cursor_pos = self.output_file_panel.value.len();
+        self.pending_profile_selection = Some(profile.selected_files);
+        self.reload_files_needed = true;
+    }
+
     async fn handle_enter(&mut self) {
         match self.focused_panel {
             FocusedPanel::SourceFiles => {
-                // Step 1: move focus to Output
-                self.focused_panel = self.focused_panel.next_panel(self);
+                // Step 1: move focus to Output (skipping Preview, which Tab
+                // cycles through but Enter here is not meant to land on)
+                self.focused_panel = FocusedPanel::Output;
 
                 // Steps 2 & 3: update Files panel title to "counting" & set items to "..."
                 self.source_files_panel.update_title_counting();
@@ -346,6 +579,7 @@ impl App {
     pub async fn reload_files_immediate(&mut self) {
         self.reload_files_needed = false;
         let path = self.source_path_panel.value.clone();
+        self.setup_watcher(&path);
         let ts_result = create_text_source(&path).await;
         if let Ok(ts) = ts_result {
             self.text_source = Some(Arc::from(ts));
@@ -360,6 +594,14 @@ impl App {
             self.text_source = None;
             self.loaded_files.clear();
         }
+        // Drop files matched by .gitignore/.ignore rules at the root and by the
+        // user's exclude globs, and surface how many were hidden in the title.
+        self.exclude_globs = self.filters_panel.exclude_globs();
+        let matcher = excludes::ExcludeMatcher::build(&path, &self.exclude_globs);
+        let before = self.loaded_files.len();
+        self.loaded_files.retain(|f| !matcher.is_excluded(&f.path));
+        let ignored = before - self.loaded_files.len();
+        self.source_files_panel.set_ignored_count(ignored);
         self.filters_panel.init_values(
             &self.loaded_files,
             &mut self.selected_extensions,
@@ -369,6 +611,16 @@ impl App {
             &self.loaded_files,
             &mut self.selected_files
         );
+        if let Some(saved) = self.pending_profile_selection.take() {
+            // Keep only files that still exist in the freshly loaded index so a
+            // profile referencing since-deleted files degrades gracefully.
+            let available: HashSet<String> =
+                self.loaded_files.iter().map(|f| f.path.clone()).collect();
+            self.selected_files = saved.intersection(&available).cloned().collect();
+            self.recompute_selected_extensions();
+            self.source_files_panel.update_title_sum(&self.selected_files);
+            self.start_token_count_for_selected_files();
+        }
     }
 
     pub async fn merge_immediate(&mut self) {
@@ -389,6 +641,42 @@ impl App {
         }
     }
 
+    /// Shared tail for every bulk selection command: bring `selected_extensions`
+    /// back into agreement with `selected_files`, refresh the Files panel title,
+    /// and queue any freshly-selected files for token counting.
+    fn after_bulk_selection(&mut self) {
+        self.recompute_selected_extensions();
+        self.source_files_panel.update_title_sum(&self.selected_files);
+        self.start_token_count_for_selected_files();
+    }
+
+    /// An extension is considered selected exactly when every loaded file with
+    /// that extension is selected, mirroring the invariant `toggle_selected`
+    /// maintains for individual toggles.
+    fn recompute_selected_extensions(&mut self) {
+        let ext_of = |path: &str| {
+            std::path::Path::new(path)
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("")
+                .to_string()
+        };
+        let mut total: HashMap<String, usize> = HashMap::new();
+        let mut chosen: HashMap<String, usize> = HashMap::new();
+        for f in &self.loaded_files {
+            let ext = ext_of(&f.path);
+            *total.entry(ext.clone()).or_insert(0) += 1;
+            if self.selected_files.contains(&f.path) {
+                *chosen.entry(ext).or_insert(0) += 1;
+            }
+        }
+        self.selected_extensions = total
+            .into_iter()
+            .filter(|(ext, count)| chosen.get(ext).copied().unwrap_or(0) == *count)
+            .map(|(ext, _)| ext)
+            .collect();
+    }
+
     fn set_cursor_to_end(&mut self) {
         match self.focused_panel {
             FocusedPanel::SourcePath => {
@@ -401,17 +689,6 @@ impl App {
         }
     }
 
-    pub async fn reload_file_content(&self, sf: &SourceFile) -> Result<String, String> {
-        if let Some(ts) = &self.text_source {
-            match ts.get_file_content(sf).await {
-                Ok(c) => Ok(c),
-                Err(e) => Err(e.to_string()),
-            }
-        } else {
-            Err("No text source available".to_string())
-        }
-    }
-
     fn start_token_count_for_selected_files(&mut self) {
         if self.text_source.is_none() {
             return;
@@ -446,6 +723,111 @@ impl App {
         }
     }
 
+    /// Kick off an async preview fetch when the Source Files highlight has moved
+    /// to a different file than the one currently shown. Mirrors
+    /// `start_token_count_for_selected_files`: the content is fetched *and*
+    /// syntax-highlighted on a background task and delivered through
+    /// `preview_tx`, so neither the read nor the (potentially slow) highlight
+    /// pass ever runs on the UI thread.
+    fn refresh_preview(&mut self) {
+        let highlighted = self
+            .source_files_panel
+            .highlighted_file(&self.loaded_files)
+            .map(|f| f.path.clone());
+        if highlighted == self.preview_panel.path {
+            return;
+        }
+        match highlighted {
+            None => {
+                self.preview_panel.path = None;
+                self.preview_panel.loading = false;
+            }
+            Some(path) => {
+                let sf = self.loaded_files.iter().find(|f| f.path == path).cloned();
+                self.preview_panel.set_loading(path.clone());
+                if let (Some(ts), Some(sf)) = (&self.text_source, sf) {
+                    let ts_for_async = Arc::clone(ts);
+                    let (syntax_set, theme_set) = self.preview_panel.syntax_resources();
+                    let tx = self.preview_tx.clone();
+                    tokio::spawn(async move {
+                        let res = match ts_for_async.get_file_content(&sf).await {
+                            Ok(content) => Ok(preview::highlight(&syntax_set, &theme_set, &path, &content)),
+                            Err(e) => Err(e.to_string()),
+                        };
+                        if let Err(e) = tx.send((path, res)) {
+                            log::error!("Error sending preview result: {}", e);
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    fn process_preview_results(&mut self) {
+        while let Ok((path, result)) = self.preview_rx.try_recv() {
+            self.preview_panel.set_content(&path, result);
+        }
+    }
+
+    /// (Re)establish the recursive filesystem watcher on `path`. A watcher is
+    /// only rebuilt when the path actually changes, so repeated reloads of the
+    /// same directory don't churn the underlying inotify/fsevent handle.
+    fn setup_watcher(&mut self, path: &str) {
+        if self.watched_path.as_deref() == Some(path) && self.watcher.is_some() {
+            return;
+        }
+        self.watcher = None;
+        self.watched_path = None;
+        let tx = self.fs_event_tx.clone();
+        let mut watcher = match notify::recommended_watcher(move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) {
+                    let paths = event
+                        .paths
+                        .iter()
+                        .filter_map(|p| p.to_str().map(|s| s.to_string()))
+                        .collect();
+                    let _ = tx.send(FsEvent { paths });
+                }
+            }
+        }) {
+            Ok(w) => w,
+            Err(e) => {
+                log::error!("Failed to create filesystem watcher: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = watcher.watch(std::path::Path::new(path), RecursiveMode::Recursive) {
+            log::error!("Failed to watch {}: {}", path, e);
+            return;
+        }
+        self.watcher = Some(watcher);
+        self.watched_path = Some(path.to_string());
+    }
+
+    /// Drain watcher events, invalidate the token count of any changed file, and
+    /// schedule a debounced reload so a burst of events triggers a single
+    /// refresh rather than one per change.
+    fn process_fs_events(&mut self) {
+        while let Ok(event) = self.fs_event_rx.try_recv() {
+            for path in &event.paths {
+                self.source_files_panel.mark_not_counted(path);
+            }
+            self.fs_reload_pending = Some(Instant::now());
+        }
+        if let Some(last) = self.fs_reload_pending {
+            if last.elapsed() >= FS_DEBOUNCE {
+                self.fs_reload_pending = None;
+                if !self.processing {
+                    self.reload_files_needed = true;
+                }
+            }
+        }
+    }
+
     fn process_token_count_results(&mut self) {
         while let Ok((path, result)) = self.token_count_rx.try_recv() {
             self.source_files_panel.set_count_result(&path, result);
@@ -0,0 +1,136 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use ansi_to_tui::IntoText;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Text,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::{as_24_bit_terminal_escaped, LinesWithEndings};
+
+/// Apply syntax highlighting to `content` and pre-render it to a ratatui
+/// `Text`. This does the syntect work, which is too slow to run on the UI
+/// thread for a large file — callers run it on the same background task that
+/// fetches the content (see `App::refresh_preview`) and send the finished
+/// `Text` back over `preview_tx`, so `PreviewPanel::set_content` only ever
+/// does a cheap assignment.
+pub fn highlight(syntax_set: &SyntaxSet, theme_set: &ThemeSet, path: &str, content: &str) -> Text<'static> {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    let syntax = syntax_set
+        .find_syntax_by_extension(ext)
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+    let theme = &theme_set.themes["base16-ocean.dark"];
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    let mut ansi = String::new();
+    for line in LinesWithEndings::from(content) {
+        match highlighter.highlight_line(line, syntax_set) {
+            Ok(ranges) => ansi.push_str(&as_24_bit_terminal_escaped(&ranges[..], false)),
+            Err(_) => ansi.push_str(line),
+        }
+    }
+    // Reset so the trailing escape doesn't bleed into the border.
+    ansi.push_str("\x1b[0m");
+    ansi.into_text()
+        .unwrap_or_else(|_| Text::from(content.to_string()))
+}
+
+/// Right-hand pane that shows the content of the file currently highlighted in
+/// the Source Files panel. Content is fetched *and* highlighted asynchronously
+/// (see the `preview_tx/rx` channel on `App`) so the UI never blocks; while a
+/// fetch is in flight the panel shows a "loading…" placeholder.
+pub struct PreviewPanel {
+    /// Path of the file the currently displayed (or loading) content belongs to.
+    /// `None` means nothing is selected yet.
+    pub path: Option<String>,
+    /// Highlighted lines, pre-rendered to a ratatui `Text`.
+    lines: Text<'static>,
+    /// Whether an async fetch for `path` is currently outstanding.
+    pub loading: bool,
+    /// Vertical scroll offset in lines.
+    pub scroll: u16,
+    syntax_set: Arc<SyntaxSet>,
+    theme_set: Arc<ThemeSet>,
+}
+
+impl PreviewPanel {
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            lines: Text::default(),
+            loading: false,
+            scroll: 0,
+            syntax_set: Arc::new(SyntaxSet::load_defaults_newlines()),
+            theme_set: Arc::new(ThemeSet::load_defaults()),
+        }
+    }
+
+    /// Clone of the syntax resources, for handing to the background task that
+    /// fetches and highlights the next file.
+    pub fn syntax_resources(&self) -> (Arc<SyntaxSet>, Arc<ThemeSet>) {
+        (Arc::clone(&self.syntax_set), Arc::clone(&self.theme_set))
+    }
+
+    /// Mark `path` as the preview target and show the loading placeholder until
+    /// the async result arrives.
+    pub fn set_loading(&mut self, path: String) {
+        self.path = Some(path);
+        self.lines = Text::default();
+        self.loading = true;
+        self.scroll = 0;
+    }
+
+    /// Apply an async fetch-and-highlight result. Late results for a file that
+    /// is no longer the highlighted one are dropped so a slow fetch can't
+    /// clobber a newer preview.
+    pub fn set_content(&mut self, path: &str, result: Result<Text<'static>, String>) {
+        if self.path.as_deref() != Some(path) {
+            return;
+        }
+        self.loading = false;
+        match result {
+            Ok(lines) => self.lines = lines,
+            Err(e) => {
+                self.lines = Text::from(format!("<failed to load preview: {}>", e));
+            }
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        let max_scroll = self.lines.lines.len().saturating_sub(1) as u16;
+        self.scroll = self.scroll.saturating_add(10).min(max_scroll);
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(10);
+    }
+
+    pub fn draw(&self, f: &mut Frame, area: Rect, focused: bool) {
+        let border_style = if focused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let block = Block::default()
+            .title("Preview")
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let body: Text = if self.loading {
+            Text::from("loading…")
+        } else if self.path.is_none() {
+            Text::default()
+        } else {
+            self.lines.clone()
+        };
+        let paragraph = Paragraph::new(body).block(block).scroll((self.scroll, 0));
+        f.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,253 @@
+//! Named selection profiles ("bookmarks").
+//!
+//! A profile captures the full working set for a project — source path, filter
+//! configuration, selected extensions and files, and the output destination —
+//! so users moving between repositories don't have to rebuild their selection
+//! every session. Profiles are stored as one TOML file per name under the XDG
+//! config directory.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::input::FilterConfig;
+
+/// A persisted working set. `output_destination` is stored as its string label
+/// so the on-disk format stays stable regardless of the enum's representation.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Profile {
+    pub source_path: String,
+    pub selected_extensions: HashSet<String>,
+    pub selected_files: HashSet<String>,
+    pub output_destination: String,
+    pub output_path: String,
+    // Table-typed fields must come last: the toml serializer errors
+    // (`ValueAfterTable`) if a scalar/array field follows a `[table]`.
+    pub filter_config: FilterConfig,
+}
+
+/// On-disk TOML store rooted at `$XDG_CONFIG_HOME/aianvil/profiles`.
+pub struct ProfileStore {
+    dir: PathBuf,
+}
+
+impl ProfileStore {
+    pub fn new() -> Self {
+        let dir = xdg::BaseDirectories::with_prefix("aianvil")
+            .ok()
+            .and_then(|b| b.create_config_directory("profiles").ok())
+            .unwrap_or_else(|| PathBuf::from(".aianvil/profiles"));
+        Self { dir }
+    }
+
+    fn path_for(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.toml", name))
+    }
+
+    /// Names of all stored profiles, sorted alphabetically.
+    pub fn list(&self) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|e| {
+                let path = e.path();
+                if path.extension().and_then(|x| x.to_str()) == Some("toml") {
+                    path.file_stem()
+                        .and_then(|s| s.to_str())
+                        .map(|s| s.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    pub fn load(&self, name: &str) -> Result<Profile, String> {
+        let raw = std::fs::read_to_string(self.path_for(name)).map_err(|e| e.to_string())?;
+        toml::from_str(&raw).map_err(|e| e.to_string())
+    }
+
+    pub fn save(&self, name: &str, profile: &Profile) -> Result<(), String> {
+        let raw = toml::to_string_pretty(profile).map_err(|e| e.to_string())?;
+        std::fs::write(self.path_for(name), raw).map_err(|e| e.to_string())
+    }
+
+    pub fn delete(&self, name: &str) -> Result<(), String> {
+        std::fs::remove_file(self.path_for(name)).map_err(|e| e.to_string())
+    }
+}
+
+/// What the modal wants the app to do once a key has been handled.
+pub enum ProfileAction {
+    None,
+    Close,
+    Load(String),
+    Save(String),
+    Delete(String),
+}
+
+enum Mode {
+    Browse,
+    Naming(String),
+}
+
+/// Modal panel listing profiles and offering load / save / delete.
+pub struct ProfilesPanel {
+    pub store: ProfileStore,
+    names: Vec<String>,
+    cursor: usize,
+    mode: Mode,
+}
+
+impl ProfilesPanel {
+    pub fn new() -> Self {
+        let store = ProfileStore::new();
+        let names = store.list();
+        Self {
+            store,
+            names,
+            cursor: 0,
+            mode: Mode::Browse,
+        }
+    }
+
+    /// Re-read the list from disk (call after a save or delete).
+    pub fn refresh(&mut self) {
+        self.names = self.store.list();
+        if self.cursor >= self.names.len() {
+            self.cursor = self.names.len().saturating_sub(1);
+        }
+    }
+
+    pub fn handle_input(&mut self, key_event: KeyEvent) -> ProfileAction {
+        match &mut self.mode {
+            Mode::Naming(buf) => match key_event.code {
+                KeyCode::Esc => {
+                    self.mode = Mode::Browse;
+                    ProfileAction::None
+                }
+                KeyCode::Enter => {
+                    let name = buf.trim().to_string();
+                    self.mode = Mode::Browse;
+                    if name.is_empty() {
+                        ProfileAction::None
+                    } else {
+                        ProfileAction::Save(name)
+                    }
+                }
+                KeyCode::Backspace => {
+                    buf.pop();
+                    ProfileAction::None
+                }
+                KeyCode::Char(c) => {
+                    buf.push(c);
+                    ProfileAction::None
+                }
+                _ => ProfileAction::None,
+            },
+            Mode::Browse => match key_event.code {
+                KeyCode::Esc => ProfileAction::Close,
+                KeyCode::Up => {
+                    self.cursor = self.cursor.saturating_sub(1);
+                    ProfileAction::None
+                }
+                KeyCode::Down => {
+                    if self.cursor + 1 < self.names.len() {
+                        self.cursor += 1;
+                    }
+                    ProfileAction::None
+                }
+                KeyCode::Enter => self
+                    .names
+                    .get(self.cursor)
+                    .map(|n| ProfileAction::Load(n.clone()))
+                    .unwrap_or(ProfileAction::None),
+                KeyCode::Char('s') => {
+                    self.mode = Mode::Naming(String::new());
+                    ProfileAction::None
+                }
+                KeyCode::Char('d') => self
+                    .names
+                    .get(self.cursor)
+                    .map(|n| ProfileAction::Delete(n.clone()))
+                    .unwrap_or(ProfileAction::None),
+                _ => ProfileAction::None,
+            },
+        }
+    }
+
+    pub fn draw(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title("Profiles")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        f.render_widget(Clear, area);
+        match &self.mode {
+            Mode::Naming(buf) => {
+                let paragraph = Paragraph::new(format!("Save as: {}_", buf)).block(block);
+                f.render_widget(paragraph, area);
+            }
+            Mode::Browse => {
+                let items: Vec<ListItem> = self
+                    .names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| {
+                        let style = if i == self.cursor {
+                            Style::default().add_modifier(Modifier::REVERSED)
+                        } else {
+                            Style::default()
+                        };
+                        ListItem::new(Line::from(Span::styled(name.clone(), style)))
+                    })
+                    .collect();
+                let list = List::new(items).block(block);
+                f.render_widget(list, area);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_then_load_round_trips_a_profile() {
+        let dir = std::env::temp_dir().join(format!("profiles-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = ProfileStore { dir: dir.clone() };
+
+        let profile = Profile {
+            source_path: "/tmp/project".to_string(),
+            selected_extensions: HashSet::from(["rs".to_string()]),
+            selected_files: HashSet::from(["src/main.rs".to_string()]),
+            output_destination: "Clipboard".to_string(),
+            output_path: String::new(),
+            filter_config: FilterConfig::new(),
+        };
+
+        store.save("round-trip", &profile).unwrap();
+        let loaded = store.load("round-trip").unwrap();
+
+        assert_eq!(loaded.source_path, profile.source_path);
+        assert_eq!(loaded.selected_extensions, profile.selected_extensions);
+        assert_eq!(loaded.selected_files, profile.selected_files);
+        assert_eq!(loaded.output_destination, profile.output_destination);
+        assert_eq!(loaded.output_path, profile.output_path);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}